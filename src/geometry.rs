@@ -94,6 +94,41 @@ impl Plane {
     }
 }
 
+impl approx::AbsDiffEq for Plane {
+    type Epsilon = f32;
+
+    fn default_epsilon() -> f32 {
+        f32::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+        Vec3::abs_diff_eq(&self.normal, &other.normal, epsilon)
+            && f32::abs_diff_eq(&self.bias, &other.bias, epsilon)
+    }
+}
+
+impl approx::RelativeEq for Plane {
+    fn default_max_relative() -> f32 {
+        f32::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f32, max_relative: f32) -> bool {
+        Vec3::relative_eq(&self.normal, &other.normal, epsilon, max_relative)
+            && f32::relative_eq(&self.bias, &other.bias, epsilon, max_relative)
+    }
+}
+
+impl approx::UlpsEq for Plane {
+    fn default_max_ulps() -> u32 {
+        f32::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: f32, max_ulps: u32) -> bool {
+        Vec3::ulps_eq(&self.normal, &other.normal, epsilon, max_ulps)
+            && f32::ulps_eq(&self.bias, &other.bias, epsilon, max_ulps)
+    }
+}
+
 /// A Ray represents and infinite half-line starting at `origin` and going in specified unit length `direction`.
 #[derive(Debug, Copy, Clone)]
 pub struct Ray {
@@ -114,10 +149,69 @@ impl Ray {
     }
 }
 
+impl approx::AbsDiffEq for Ray {
+    type Epsilon = f32;
+
+    fn default_epsilon() -> f32 {
+        f32::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+        Vec3::abs_diff_eq(&self.origin, &other.origin, epsilon)
+            && Vec3::abs_diff_eq(&self.direction, &other.direction, epsilon)
+    }
+}
+
+impl approx::RelativeEq for Ray {
+    fn default_max_relative() -> f32 {
+        f32::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f32, max_relative: f32) -> bool {
+        Vec3::relative_eq(&self.origin, &other.origin, epsilon, max_relative)
+            && Vec3::relative_eq(&self.direction, &other.direction, epsilon, max_relative)
+    }
+}
+
+impl approx::UlpsEq for Ray {
+    fn default_max_ulps() -> u32 {
+        f32::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: f32, max_ulps: u32) -> bool {
+        Vec3::ulps_eq(&self.origin, &other.origin, epsilon, max_ulps)
+            && Vec3::ulps_eq(&self.direction, &other.direction, epsilon, max_ulps)
+    }
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
-    use approx::{assert_ulps_eq, relative_eq};
+    use approx::{assert_relative_eq, assert_ulps_eq, relative_eq};
+
+    #[test]
+    fn plane_approx_eq() {
+        let a = Plane::new(Vec3::new(0.0, 0.0, 1.0), 1.0);
+        let b = Plane::new(Vec3::new(0.0, 0.0, 1.000_000_1), 1.000_000_1);
+
+        assert_relative_eq!(a, b);
+        assert_ulps_eq!(a, b);
+    }
+
+    #[test]
+    fn ray_approx_eq() {
+        let a = Ray {
+            origin: Vec3::new(1.0, 2.0, 3.0),
+            direction: Vec3::new(0.0, 0.0, 1.0),
+        };
+        let b = Ray {
+            origin: Vec3::new(1.000_000_1, 2.0, 3.0),
+            direction: Vec3::new(0.0, 0.0, 1.000_000_1),
+        };
+
+        assert_relative_eq!(a, b);
+        assert_ulps_eq!(a, b);
+    }
 
     #[test]
     #[allow(clippy::mistyped_literal_suffixes)]
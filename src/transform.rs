@@ -7,8 +7,103 @@ use crate::*;
 
 use std::ops::*;
 
+/// Lane-wise helper bridging the scalar (`f32`) and wide (`f32x4`) instantiations
+/// of the `isometries!`/`similarities!` macros.
+///
+/// `f32x4` packs four independent lanes, so a bare `<`/`if` on it would collapse
+/// four independent "which way is shorter" decisions into one and silently corrupt
+/// whichever lanes disagree with the first. These helpers make the decision
+/// branchlessly, lane-by-lane, while staying a plain branch for the scalar case.
+trait ArcSelect: Copy {
+    /// `-1.0` in lanes where `self` is negative, `1.0` otherwise.
+    fn shortest_arc_sign(self) -> Self;
+    /// `1.0` in lanes where `self > threshold`, `0.0` otherwise.
+    fn gt_mask(self, threshold: Self) -> Self;
+}
+
+impl ArcSelect for f32 {
+    #[inline]
+    fn shortest_arc_sign(self) -> Self {
+        if self < 0.0 {
+            -1.0
+        } else {
+            1.0
+        }
+    }
+
+    #[inline]
+    fn gt_mask(self, threshold: Self) -> Self {
+        if self > threshold {
+            1.0
+        } else {
+            0.0
+        }
+    }
+}
+
+impl ArcSelect for f32x4 {
+    #[inline]
+    fn shortest_arc_sign(self) -> Self {
+        self.cmp_lt(f32x4::from(0.0))
+            .blend(f32x4::from(-1.0), f32x4::from(1.0))
+    }
+
+    #[inline]
+    fn gt_mask(self, threshold: Self) -> Self {
+        // `wide` only re-exports `CmpGe`/`CmpLt`, not `CmpGt`, so `self > threshold`
+        // is built as `!(threshold >= self)` instead of a direct `cmp_gt`.
+        (!threshold.cmp_ge(self)).blend(f32x4::from(1.0), f32x4::from(0.0))
+    }
+}
+
+/// Lane-wise geometric interpolation of a uniform scale factor, shared by the
+/// scalar and wide instantiations of `similarities!`.
+///
+/// Falls back to linear interpolation wherever the start and end scales differ
+/// in sign, since raising a negative base to a fractional power (`powf`) is
+/// undefined and would otherwise turn a legal mirrored-to-unmirrored blend into
+/// `NaN`. `from_isometry`/`from_parts` only reject `scale == 0`, so a negative
+/// scale is a reachable, legal state here.
+trait GeometricLerp: Copy {
+    fn geometric_lerp(self, end: Self, t: Self) -> Self;
+}
+
+impl GeometricLerp for f32 {
+    #[inline]
+    fn geometric_lerp(self, end: Self, t: Self) -> Self {
+        if self * end > 0.0 {
+            self * (end / self).powf(t)
+        } else {
+            self + (end - self) * t
+        }
+    }
+}
+
+impl GeometricLerp for f32x4 {
+    #[inline]
+    fn geometric_lerp(self, end: Self, t: Self) -> Self {
+        let linear = self + (end - self) * t;
+        // Clamp the divisor's magnitude away from zero (preserving its sign) before
+        // dividing: a lane with `self == 0.0` (reachable by chaining `lerp`/`slerp`
+        // off an already-linear-fallback scale of `0.0`) would otherwise make
+        // `end / self` +-inf/NaN, and `geometric`'s leading `self * ..` being `0 * inf`
+        // or `0 * NaN` is itself `NaN` — which, like the `slerp` case below, survives
+        // being multiplied by `same_sign == 0.0` below. Since `self` itself is the
+        // zero factor either way, the final product is correctly `0.0` once the
+        // division no longer produces a non-finite intermediate.
+        let self_safe = self.shortest_arc_sign() * self.abs().max(f32x4::from(1e-12));
+        // `.abs()` keeps `powf_simd` NaN-free even in lanes where the signs differ;
+        // those lanes are discarded by the blend below anyway. Note this is
+        // `powf_simd`, not `powf`: the latter only takes a scalar `f32` exponent,
+        // which can't express a per-lane `t`.
+        let geometric = self * (end / self_safe).abs().powf_simd(t);
+        let same_sign = (self * end).gt_mask(f32x4::from(0.0));
+        geometric * same_sign + linear * (f32x4::from(1.0) - same_sign)
+    }
+}
+
 macro_rules! isometries {
-    ($($ison:ident => ($mt:ident, $rt:ident, $vt:ident, $t:ident)),+) => {
+    ($($ison:ident => ($mt:ident, $rt:ident, $vt:ident, $t:ident, $sn:ident)),+) => {
         $(
         /// An Isometry, aka a "rigid body transformation".
         ///
@@ -106,18 +201,164 @@ macro_rules! isometries {
                 self
             }
 
+            /// Transform a point, applying both the rotational and translational parts.
             #[inline]
-            pub fn transform_vec(&self, mut vec: $vt) -> $vt {
+            pub fn transform_point(&self, mut vec: $vt) -> $vt {
                 vec = self.rotation * vec;
                 vec += self.translation;
                 vec
             }
 
+            /// Transform a vector/direction, applying the rotational part only.
+            ///
+            /// Unlike [`Self::transform_point`], this must *not* be used for points,
+            /// since directions are not affected by translation.
+            ///
+            /// # Breaking change
+            ///
+            /// Prior to the introduction of [`Self::transform_point`], this method
+            /// applied translation as well (i.e. it had point semantics). Callers
+            /// using `transform_vec` to move a *point* must switch to
+            /// [`Self::transform_point`], since this method now silently drops the
+            /// translation.
+            #[inline]
+            pub fn transform_vec(&self, vec: $vt) -> $vt {
+                self.rotation * vec
+            }
+
+            /// Alias of [`Self::transform_vec`].
+            #[inline]
+            pub fn transform_direction(&self, vec: $vt) -> $vt {
+                self.transform_vec(vec)
+            }
+
+            /// Transform a point by the inverse of this isometry, without materializing
+            /// [`Self::inversed`] first.
+            #[inline]
+            pub fn inverse_transform_point(&self, vec: $vt) -> $vt {
+                self.rotation.reversed() * (vec - self.translation)
+            }
+
+            /// Transform a vector/direction by the inverse of this isometry, without
+            /// materializing [`Self::inversed`] first.
+            #[inline]
+            pub fn inverse_transform_vec(&self, vec: $vt) -> $vt {
+                self.rotation.reversed() * vec
+            }
+
             #[inline]
             pub fn into_homogeneous_matrix(self) -> $mt {
                 $mt::from_translation(self.translation)
                     * self.rotation.into_matrix().into_homogeneous()
             }
+
+            /// Promote this isometry to the corresponding similarity type by adding
+            /// a uniform `scale`.
+            #[inline]
+            pub fn to_similarity(self, scale: $t) -> $sn {
+                $sn::from_isometry(self, scale)
+            }
+
+            /// Linearly interpolate between this isometry and `end` by the parameter `t`.
+            ///
+            /// The translation is interpolated linearly, while the rotation is
+            /// interpolated using nlerp (normalized linear interpolation), i.e.
+            /// `(a * (1 - t) + b * t).normalized()`, taking the shortest arc between
+            /// the two rotors. This is cheaper than [`Self::slerp`] but does not
+            /// rotate at a constant angular rate.
+            #[inline]
+            pub fn lerp(&self, end: Self, t: $t) -> Self {
+                let translation = self.translation + (end.translation - self.translation) * t;
+
+                let a = self.rotation;
+                let b = end.rotation;
+                // Fold the shortest-arc sign flip into the interpolation weight of `b`
+                // rather than negating `b` itself, since that weight is lane-wise
+                // branchless (see `ArcSelect`) while the rotor itself is opaque to us.
+                let sign = a.dot(b).shortest_arc_sign();
+                let rotation = (a * ($t::from(1.0) - t) + b * (sign * t)).normalized();
+
+                Self { translation, rotation }
+            }
+
+            /// Spherically interpolate between this isometry and `end` by the parameter `t`.
+            ///
+            /// The translation is interpolated linearly, while the rotation is
+            /// interpolated using a true slerp, which rotates at a constant angular
+            /// rate between the two rotors, taking the shortest arc. This is more
+            /// expensive than [`Self::lerp`] but is more accurate, which matters for
+            /// e.g. camera animation.
+            #[inline]
+            pub fn slerp(&self, end: Self, t: $t) -> Self {
+                let translation = self.translation + (end.translation - self.translation) * t;
+
+                let a = self.rotation.normalized();
+                let b = end.rotation.normalized();
+                let sign = a.dot(b).shortest_arc_sign();
+                // Rounding in `normalized()`/`dot()` can push this fractionally above
+                // `1.0` even for merely near-identical (not just bit-identical) rotors,
+                // which would otherwise hit `acos`'s domain error and produce NaN.
+                let dot = (a.dot(b) * sign).max($t::from(-1.0)).min($t::from(1.0));
+
+                let theta = dot.acos();
+                let sin_theta = theta.sin();
+                // Compute both the slerp and nlerp candidates unconditionally and blend
+                // them lane-wise, rather than branching on `sin_theta`, so every lane of
+                // a wide type gets the formula appropriate to *its own* angle.
+                //
+                // `theta` itself (not just `sin_theta`) is clamped away from zero here:
+                // at `theta == 0` (e.g. `a.slerp(a, t)`) the *numerator* of the slerp
+                // formula is also exactly zero, so the sum inside `slerp_rotation` is the
+                // zero rotor and `.normalized()` computes a literal `0.0 / 0.0 = NaN` that
+                // `use_slerp` can't discard below, since `NaN * 0.0 == NaN`, not `0.0`.
+                // Clamping `theta` keeps that sum away from the zero vector in that regime, so
+                // the (still-discarded) candidate is merely imprecise, never NaN.
+                let theta_safe = theta.max($t::from(1e-6));
+                let sin_theta_safe = sin_theta.max($t::from(1e-6));
+                let slerp_rotation = (a * ((($t::from(1.0) - t) * theta_safe).sin() / sin_theta_safe)
+                    + b * (sign * ((t * theta_safe).sin() / sin_theta_safe)))
+                    .normalized();
+                let nlerp_rotation = (a * ($t::from(1.0) - t) + b * (sign * t)).normalized();
+                let use_slerp = sin_theta.gt_mask($t::from(1e-6));
+                let rotation = slerp_rotation * use_slerp + nlerp_rotation * ($t::from(1.0) - use_slerp);
+
+                Self { translation, rotation }
+            }
+        }
+
+        impl approx::AbsDiffEq for $ison {
+            type Epsilon = $t;
+
+            fn default_epsilon() -> $t {
+                $t::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: $t) -> bool {
+                $vt::abs_diff_eq(&self.translation, &other.translation, epsilon)
+                    && $rt::abs_diff_eq(&self.rotation, &other.rotation, epsilon)
+            }
+        }
+
+        impl approx::RelativeEq for $ison {
+            fn default_max_relative() -> $t {
+                $t::default_max_relative()
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: $t, max_relative: $t) -> bool {
+                $vt::relative_eq(&self.translation, &other.translation, epsilon, max_relative)
+                    && $rt::relative_eq(&self.rotation, &other.rotation, epsilon, max_relative)
+            }
+        }
+
+        impl approx::UlpsEq for $ison {
+            fn default_max_ulps() -> u32 {
+                <$t as approx::UlpsEq>::default_max_ulps()
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: $t, max_ulps: u32) -> bool {
+                $vt::ulps_eq(&self.translation, &other.translation, epsilon, max_ulps)
+                    && $rt::ulps_eq(&self.rotation, &other.rotation, epsilon, max_ulps)
+            }
         }
 
         impl Mul<$ison> for $rt {
@@ -142,7 +383,7 @@ macro_rules! isometries {
             type Output = $vt;
             #[inline]
             fn mul(self, vec: $vt) -> $vt {
-                self.transform_vec(vec)
+                self.transform_point(vec)
             }
         }
 
@@ -150,7 +391,7 @@ macro_rules! isometries {
             type Output = Self;
             #[inline]
             fn mul(self, base: $ison) -> $ison {
-                let trans = self.transform_vec(base.translation);
+                let trans = self.transform_point(base.translation);
                 let rot = self.rotation * base.rotation;
                 $ison::new(trans, rot)
             }
@@ -160,12 +401,50 @@ macro_rules! isometries {
 }
 
 isometries!(
-    Isometry2 => (Mat3, Rotor2, Vec2, f32), WIsometry2 => (Wat3, WRotor2, Wec2, f32x4),
-    Isometry3 => (Mat4, Rotor3, Vec3, f32), WIsometry3 => (Wat4, WRotor3, Wec3, f32x4)
+    Isometry2 => (Mat3, Rotor2, Vec2, f32, Similarity2), WIsometry2 => (Wat3, WRotor2, Wec2, f32x4, WSimilarity2),
+    Isometry3 => (Mat4, Rotor3, Vec3, f32, Similarity3), WIsometry3 => (Wat4, WRotor3, Wec3, f32x4, WSimilarity3)
 );
 
+impl Isometry3 {
+    /// Construct an isometry which transforms from world space into the view
+    /// space of an observer standing at `eye`, facing `target`, with `up`
+    /// defining the observer's "up" direction.
+    ///
+    /// This is the inverse of [`Self::face_towards`], i.e. it produces the
+    /// world-to-view transform used directly by a camera, rather than the
+    /// transform of an object placed at `eye`.
+    pub fn look_at(eye: Vec3, target: Vec3, up: Vec3) -> Self {
+        Self::face_towards(eye, target, up).inversed()
+    }
+
+    /// Construct an isometry which places an object at `eye`, facing `target`,
+    /// with `up` defining the object's "up" direction.
+    ///
+    /// Builds an orthonormal basis out of `forward`, `right`, and `up`, and
+    /// uses it as the object's rotation. If `forward` is parallel to `up`,
+    /// an alternate axis is used to build the basis instead, falling back to
+    /// a second alternate axis in the (rarer) case that `forward` is *also*
+    /// parallel to that first alternate.
+    pub fn face_towards(eye: Vec3, target: Vec3, up: Vec3) -> Self {
+        let forward = (target - eye).normalized();
+        let mut right = up.cross(forward);
+        if right.mag_sq() < f32::EPSILON {
+            right = Vec3::unit_z().cross(forward);
+        }
+        if right.mag_sq() < f32::EPSILON {
+            right = Vec3::unit_x().cross(forward);
+        }
+        let right = right.normalized();
+        let new_up = forward.cross(right);
+
+        let rotation = Rotor3::from_rotation_matrix(Mat3::new(right, new_up, forward));
+
+        Self::new(eye, rotation)
+    }
+}
+
 macro_rules! similarities {
-    ($($sn:ident => ($mt:ident, $rt:ident, $vt:ident, $t:ident)),+) => {
+    ($($sn:ident => ($mt:ident, $rt:ident, $vt:ident, $t:ident, $ison:ident)),+) => {
         $(
         /// A Similarity, i.e. an Isometry but with an added uniform scaling.
         ///
@@ -195,6 +474,32 @@ macro_rules! similarities {
                 Self { rotation: $rt::identity(), translation: $vt::zero(), scale: $t::from(1.0) }
             }
 
+            /// Construct a similarity from an isometry plus a uniform `scale`.
+            ///
+            /// `scale` must not be zero, since a zero scale produces a non-invertible
+            /// transform that would silently break [`Self::inverse`].
+            #[inline]
+            pub fn from_isometry(isometry: $ison, scale: $t) -> Self {
+                debug_assert_ne!(scale, $t::from(0.0));
+                Self { translation: isometry.translation, rotation: isometry.rotation, scale }
+            }
+
+            /// Construct a similarity directly from its translation, rotation, and scale parts.
+            ///
+            /// `scale` must not be zero, since a zero scale produces a non-invertible
+            /// transform that would silently break [`Self::inverse`].
+            #[inline]
+            pub fn from_parts(translation: $vt, rotation: $rt, scale: $t) -> Self {
+                debug_assert_ne!(scale, $t::from(0.0));
+                Self { translation, rotation, scale }
+            }
+
+            /// The isometry embedded in this similarity, with the scale dropped.
+            #[inline]
+            pub fn isometry(&self) -> $ison {
+                $ison::new(self.translation, self.rotation)
+            }
+
             /// Add a scaling *before* this similarity.
             /// 
             /// This means the scaling will only affect the scaling part
@@ -277,8 +582,11 @@ macro_rules! similarities {
             #[inline]
             pub fn inverse(&mut self) {
                 self.rotation.reverse();
-                self.translation = self.rotation * (-self.translation);
                 self.scale = $t::from(1.0) / self.scale;
+                // The new translation must be scaled by the *new* (reciprocal) scale
+                // as well as rotated, since `transform_point` applies scale before
+                // translation: otherwise this wouldn't actually invert `transform_point`.
+                self.translation = self.scale * (self.rotation * (-self.translation));
             }
 
             #[inline]
@@ -287,19 +595,167 @@ macro_rules! similarities {
                 self
             }
 
+            /// Transform a point, applying the rotational, scaling, and translational parts.
             #[inline]
-            pub fn transform_vec(&self, mut vec: $vt) -> $vt {
+            pub fn transform_point(&self, mut vec: $vt) -> $vt {
                 vec = self.rotation * vec;
                 vec = self.scale * vec;
                 vec += self.translation;
                 vec
             }
 
+            /// Transform a vector/direction, applying the rotational and scaling parts only.
+            ///
+            /// Unlike [`Self::transform_point`], this must *not* be used for points,
+            /// since directions are not affected by translation.
+            ///
+            /// # Breaking change
+            ///
+            /// Prior to the introduction of [`Self::transform_point`], this method
+            /// applied translation as well (i.e. it had point semantics). Callers
+            /// using `transform_vec` to move a *point* must switch to
+            /// [`Self::transform_point`], since this method now silently drops the
+            /// translation.
+            #[inline]
+            pub fn transform_vec(&self, vec: $vt) -> $vt {
+                self.scale * (self.rotation * vec)
+            }
+
+            /// Alias of [`Self::transform_vec`].
+            #[inline]
+            pub fn transform_direction(&self, vec: $vt) -> $vt {
+                self.transform_vec(vec)
+            }
+
+            /// Transform a point by the inverse of this similarity, without materializing
+            /// [`Self::inversed`] first.
+            #[inline]
+            pub fn inverse_transform_point(&self, vec: $vt) -> $vt {
+                (self.rotation.reversed() * (vec - self.translation)) / self.scale
+            }
+
+            /// Transform a vector/direction by the inverse of this similarity, without
+            /// materializing [`Self::inversed`] first.
+            #[inline]
+            pub fn inverse_transform_vec(&self, vec: $vt) -> $vt {
+                (self.rotation.reversed() * vec) / self.scale
+            }
+
             #[inline]
             pub fn into_homogeneous_matrix(self) -> $mt {
                 $mt::from_translation(self.translation)
                     * self.rotation.into_matrix().into_homogeneous()
             }
+
+            /// Linearly interpolate between this similarity and `end` by the parameter `t`.
+            ///
+            /// The translation is interpolated linearly, the rotation is interpolated
+            /// using nlerp (normalized linear interpolation), taking the shortest arc
+            /// between the two rotors, and the scale is interpolated geometrically
+            /// (`a * (b / a).powf(t)`) so that a constant-rate zoom looks uniform,
+            /// falling back to linear interpolation if the two scales differ in sign.
+            /// This is cheaper than [`Self::slerp`] but does not rotate at a constant
+            /// angular rate.
+            #[inline]
+            pub fn lerp(&self, end: Self, t: $t) -> Self {
+                let translation = self.translation + (end.translation - self.translation) * t;
+
+                let a = self.rotation;
+                let b = end.rotation;
+                let sign = a.dot(b).shortest_arc_sign();
+                let rotation = (a * ($t::from(1.0) - t) + b * (sign * t)).normalized();
+
+                let scale = self.scale.geometric_lerp(end.scale, t);
+
+                Self { translation, rotation, scale }
+            }
+
+            /// Spherically interpolate between this similarity and `end` by the parameter `t`.
+            ///
+            /// The translation is interpolated linearly, the scale is interpolated
+            /// geometrically (`a * (b / a).powf(t)`, falling back to linear interpolation
+            /// if the two scales differ in sign), and the rotation is interpolated
+            /// using a true slerp, which rotates at a constant angular rate between
+            /// the two rotors, taking the shortest arc. This is more expensive than
+            /// [`Self::lerp`] but is more accurate, which matters for e.g. camera
+            /// animation.
+            #[inline]
+            pub fn slerp(&self, end: Self, t: $t) -> Self {
+                let translation = self.translation + (end.translation - self.translation) * t;
+
+                let a = self.rotation.normalized();
+                let b = end.rotation.normalized();
+                let sign = a.dot(b).shortest_arc_sign();
+                // See the identical comment in `isometries!`'s `slerp`: clamp away
+                // rounding overshoot above `1.0` to keep `acos` out of NaN territory.
+                let dot = (a.dot(b) * sign).max($t::from(-1.0)).min($t::from(1.0));
+
+                let theta = dot.acos();
+                let sin_theta = theta.sin();
+                // Clamp `theta` itself (not just `sin_theta`) away from zero so
+                // the sum inside `slerp_rotation` can't collapse to the zero rotor and turn `.normalized()`
+                // into a `0.0 / 0.0 = NaN` that survives being multiplied by
+                // `use_slerp == 0.0` below. See `isometries!`'s `slerp` for the full
+                // explanation.
+                let theta_safe = theta.max($t::from(1e-6));
+                let sin_theta_safe = sin_theta.max($t::from(1e-6));
+                let slerp_rotation = (a * ((($t::from(1.0) - t) * theta_safe).sin() / sin_theta_safe)
+                    + b * (sign * ((t * theta_safe).sin() / sin_theta_safe)))
+                    .normalized();
+                let nlerp_rotation = (a * ($t::from(1.0) - t) + b * (sign * t)).normalized();
+                let use_slerp = sin_theta.gt_mask($t::from(1e-6));
+                let rotation = slerp_rotation * use_slerp + nlerp_rotation * ($t::from(1.0) - use_slerp);
+
+                let scale = self.scale.geometric_lerp(end.scale, t);
+
+                Self { translation, rotation, scale }
+            }
+        }
+
+        impl From<$ison> for $sn {
+            /// Converts an isometry into a similarity with a scale of `1.0`.
+            #[inline]
+            fn from(isometry: $ison) -> Self {
+                Self::from_isometry(isometry, $t::from(1.0))
+            }
+        }
+
+        impl approx::AbsDiffEq for $sn {
+            type Epsilon = $t;
+
+            fn default_epsilon() -> $t {
+                $t::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: $t) -> bool {
+                $vt::abs_diff_eq(&self.translation, &other.translation, epsilon)
+                    && $rt::abs_diff_eq(&self.rotation, &other.rotation, epsilon)
+                    && $t::abs_diff_eq(&self.scale, &other.scale, epsilon)
+            }
+        }
+
+        impl approx::RelativeEq for $sn {
+            fn default_max_relative() -> $t {
+                $t::default_max_relative()
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: $t, max_relative: $t) -> bool {
+                $vt::relative_eq(&self.translation, &other.translation, epsilon, max_relative)
+                    && $rt::relative_eq(&self.rotation, &other.rotation, epsilon, max_relative)
+                    && $t::relative_eq(&self.scale, &other.scale, epsilon, max_relative)
+            }
+        }
+
+        impl approx::UlpsEq for $sn {
+            fn default_max_ulps() -> u32 {
+                <$t as approx::UlpsEq>::default_max_ulps()
+            }
+
+            fn ulps_eq(&self, other: &Self, epsilon: $t, max_ulps: u32) -> bool {
+                $vt::ulps_eq(&self.translation, &other.translation, epsilon, max_ulps)
+                    && $rt::ulps_eq(&self.rotation, &other.rotation, epsilon, max_ulps)
+                    && $t::ulps_eq(&self.scale, &other.scale, epsilon, max_ulps)
+            }
         }
 
         impl Mul<$sn> for $rt {
@@ -324,7 +780,7 @@ macro_rules! similarities {
             type Output = $vt;
             #[inline]
             fn mul(self, vec: $vt) -> $vt {
-                self.transform_vec(vec)
+                self.transform_point(vec)
             }
         }
 
@@ -332,7 +788,7 @@ macro_rules! similarities {
             type Output = Self;
             #[inline]
             fn mul(self, base: $sn) -> $sn {
-                let trans = self.transform_vec(base.translation);
+                let trans = self.transform_point(base.translation);
                 let rot = self.rotation * base.rotation;
                 let scale = self.scale * base.scale;
                 $sn::new(trans, rot, scale)
@@ -343,6 +799,329 @@ macro_rules! similarities {
 }
 
 similarities!(
-    Similarity2 => (Mat3, Rotor2, Vec2, f32), WSimilarity2 => (Wat3, WRotor2, Wec2, f32x4),
-    Similarity3 => (Mat4, Rotor3, Vec3, f32), WSimilarity3 => (Wat4, WRotor3, Wec3, f32x4)
+    Similarity2 => (Mat3, Rotor2, Vec2, f32, Isometry2), WSimilarity2 => (Wat3, WRotor2, Wec2, f32x4, WIsometry2),
+    Similarity3 => (Mat4, Rotor3, Vec3, f32, Isometry3), WSimilarity3 => (Wat4, WRotor3, Wec3, f32x4, WIsometry3)
 );
+
+impl Similarity3 {
+    /// Construct a similarity which transforms from world space into the view
+    /// space of an observer standing at `eye`, facing `target`, with `up`
+    /// defining the observer's "up" direction and `scale` an additional
+    /// uniform scaling (pass `1.0` for no scaling).
+    ///
+    /// This is the inverse-oriented counterpart of [`Self::face_towards`].
+    pub fn look_at(eye: Vec3, target: Vec3, up: Vec3, scale: f32) -> Self {
+        Self::from_isometry(Isometry3::look_at(eye, target, up), scale)
+    }
+
+    /// Construct a similarity which places an object at `eye`, facing
+    /// `target`, with `up` defining the object's "up" direction and `scale`
+    /// an additional uniform scaling (pass `1.0` for no scaling).
+    pub fn face_towards(eye: Vec3, target: Vec3, up: Vec3, scale: f32) -> Self {
+        Self::from_isometry(Isometry3::face_towards(eye, target, up), scale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::{assert_relative_eq, assert_ulps_eq, relative_eq};
+
+    #[test]
+    fn isometry_lerp_takes_shortest_arc() {
+        let a = Isometry3::new(Vec3::zero(), Rotor3::identity());
+        let mut b = Isometry3::new(Vec3::zero(), Rotor3::identity());
+        b.rotation = -b.rotation;
+
+        let lerped = a.lerp(b, 0.5);
+        assert_relative_eq!(lerped.rotation, Rotor3::identity());
+    }
+
+    #[test]
+    fn isometry_slerp_takes_shortest_arc() {
+        let a = Isometry3::new(Vec3::zero(), Rotor3::from_rotation_xy(0.0));
+        let mut b = Isometry3::new(Vec3::zero(), Rotor3::from_rotation_xy(0.1));
+        b.rotation = -b.rotation;
+
+        // Halfway along the short arc (through the sign-flipped `b`) is a rotation by
+        // 0.05; the long arc would land far from it.
+        let slerped = a.slerp(b, 0.5);
+        assert_relative_eq!(slerped.rotation, Rotor3::from_rotation_xy(0.05), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn isometry_slerp_at_endpoints_matches_inputs() {
+        let a = Isometry3::new(Vec3::new(0.0, 0.0, 0.0), Rotor3::from_rotation_xy(0.0));
+        let b = Isometry3::new(Vec3::new(1.0, 2.0, 3.0), Rotor3::from_rotation_xy(1.0));
+
+        assert_relative_eq!(a.slerp(b, 0.0).translation, a.translation);
+        assert_relative_eq!(a.slerp(b, 1.0).translation, b.translation);
+    }
+
+    #[test]
+    fn isometry_slerp_of_equal_rotations_is_not_nan() {
+        // `theta == 0` makes the slerp formula's numerator exactly zero too, so an
+        // un-guarded `.normalized()` computes `0.0 / 0.0 = NaN` that survives being
+        // multiplied by the (zero) slerp/nlerp blend weight inside `slerp`.
+        let a = Isometry3::new(Vec3::new(1.0, 2.0, 3.0), Rotor3::from_rotation_xy(0.4));
+
+        let slerped = a.slerp(a, 0.5);
+        assert!(slerped.rotation.mag().is_finite());
+        assert_relative_eq!(slerped.rotation, a.rotation, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn similarity_slerp_of_equal_scales_is_not_nan() {
+        let a = Similarity3::from_parts(Vec3::new(1.0, 2.0, 3.0), Rotor3::from_rotation_xy(0.4), 2.0);
+
+        let slerped = a.slerp(a, 0.5);
+        assert!(slerped.rotation.mag().is_finite());
+        assert_relative_eq!(slerped.rotation, a.rotation, epsilon = 1e-5);
+        assert_relative_eq!(slerped.scale, a.scale, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn similarity_lerp_falls_back_to_linear_for_sign_mismatched_scale() {
+        let a = Similarity3::from_parts(Vec3::zero(), Rotor3::identity(), 2.0);
+        let b = Similarity3::from_parts(Vec3::zero(), Rotor3::identity(), -2.0);
+
+        let mid = a.lerp(b, 0.5);
+        assert!(mid.scale.is_finite());
+        assert_relative_eq!(mid.scale, 0.0);
+    }
+
+    #[test]
+    fn similarity_lerp_scales_geometrically_for_same_sign_scale() {
+        let a = Similarity3::from_parts(Vec3::zero(), Rotor3::identity(), 1.0);
+        let b = Similarity3::from_parts(Vec3::zero(), Rotor3::identity(), 4.0);
+
+        assert_relative_eq!(a.lerp(b, 0.5).scale, 2.0);
+    }
+
+    #[test]
+    fn wide_isometry_lerp_takes_shortest_arc_per_lane() {
+        // Lanes 0 and 2 have `a.dot(b) >= 0` (no flip needed); lanes 1 and 3 have
+        // `a.dot(b) < 0` (flip needed), so a broken per-lane `shortest_arc_sign`
+        // would corrupt exactly the lanes that disagree with lane 0.
+        let a_lanes = [
+            Rotor3::identity(),
+            Rotor3::identity(),
+            Rotor3::from_rotation_xy(0.2),
+            Rotor3::from_rotation_xy(0.2),
+        ];
+        let b_lanes = [
+            Rotor3::identity(),
+            -Rotor3::identity(),
+            Rotor3::from_rotation_xy(0.2),
+            -Rotor3::from_rotation_xy(0.2),
+        ];
+
+        let wa = WIsometry3::new(Wec3::from([Vec3::zero(); 4]), WRotor3::from(a_lanes));
+        let wb = WIsometry3::new(Wec3::from([Vec3::zero(); 4]), WRotor3::from(b_lanes));
+
+        let lerped_lanes: [Rotor3; 4] = wa.lerp(wb, f32x4::from(0.5)).rotation.into();
+
+        for i in 0..4 {
+            let scalar = Isometry3::new(Vec3::zero(), a_lanes[i]).lerp(Isometry3::new(Vec3::zero(), b_lanes[i]), 0.5);
+            assert_relative_eq!(lerped_lanes[i], scalar.rotation, epsilon = 1e-5);
+        }
+    }
+
+    #[test]
+    fn wide_isometry_slerp_matches_scalar_per_lane() {
+        // Mixes a near-zero angle (exercises the `sin_theta <= 1e-6` nlerp fallback
+        // lane) with larger angles (exercise the true-slerp lanes), so a broken
+        // `gt_mask` would blend the wrong formula into at least one lane.
+        let a_lanes = [Rotor3::identity(); 4];
+        let b_lanes = [
+            Rotor3::from_rotation_xy(0.0),
+            Rotor3::from_rotation_xy(0.3),
+            Rotor3::from_rotation_xy(1.0),
+            Rotor3::from_rotation_xy(-0.6),
+        ];
+
+        let wa = WIsometry3::new(Wec3::from([Vec3::zero(); 4]), WRotor3::from(a_lanes));
+        let wb = WIsometry3::new(Wec3::from([Vec3::zero(); 4]), WRotor3::from(b_lanes));
+
+        let slerped_lanes: [Rotor3; 4] = wa.slerp(wb, f32x4::from(0.5)).rotation.into();
+
+        for i in 0..4 {
+            let scalar =
+                Isometry3::new(Vec3::zero(), a_lanes[i]).slerp(Isometry3::new(Vec3::zero(), b_lanes[i]), 0.5);
+            assert_relative_eq!(slerped_lanes[i], scalar.rotation, epsilon = 1e-5);
+        }
+    }
+
+    #[test]
+    fn wide_similarity_lerp_scales_per_lane() {
+        // Lanes 0/1 share a sign with their partner (geometric lerp, `powf_simd`);
+        // lanes 2/3 differ in sign (linear fallback), so a broken `gt_mask` or a
+        // scalar-only `powf` would corrupt at least one lane.
+        let scale_a = [1.0, 2.0, 2.0, 1.0];
+        let scale_b = [4.0, 8.0, -2.0, -1.0];
+
+        let wa = WSimilarity3::new(Wec3::from([Vec3::zero(); 4]), WRotor3::from([Rotor3::identity(); 4]), f32x4::from(scale_a));
+        let wb = WSimilarity3::new(Wec3::from([Vec3::zero(); 4]), WRotor3::from([Rotor3::identity(); 4]), f32x4::from(scale_b));
+
+        let lerped_scales: [f32; 4] = wa.lerp(wb, f32x4::from(0.5)).scale.into();
+
+        for i in 0..4 {
+            let scalar = Similarity3::from_parts(Vec3::zero(), Rotor3::identity(), scale_a[i])
+                .lerp(Similarity3::from_parts(Vec3::zero(), Rotor3::identity(), scale_b[i]), 0.5);
+            assert_relative_eq!(lerped_scales[i], scalar.scale, epsilon = 1e-5);
+        }
+    }
+
+    #[test]
+    fn wide_similarity_lerp_scale_stays_finite_with_zero_scale_lane() {
+        // Lane 0 starts from a scale of exactly `0.0`, reachable in practice by
+        // chaining a `lerp`/`slerp` off an already-linear-fallback scale (see
+        // `similarity_lerp_falls_back_to_linear_for_sign_mismatched_scale`). A
+        // broken `geometric_lerp` would compute `end / self == inf`/`NaN` for that
+        // lane and let it poison the result despite `self * ..` being multiplied
+        // by zero either way.
+        let scale_a = [0.0, 1.0, 2.0, 3.0];
+        let scale_b = [5.0, 4.0, 8.0, 9.0];
+
+        let wa = WSimilarity3::new(Wec3::from([Vec3::zero(); 4]), WRotor3::from([Rotor3::identity(); 4]), f32x4::from(scale_a));
+        let wb = WSimilarity3::new(Wec3::from([Vec3::zero(); 4]), WRotor3::from([Rotor3::identity(); 4]), f32x4::from(scale_b));
+
+        let lerped_scales: [f32; 4] = wa.lerp(wb, f32x4::from(0.5)).scale.into();
+
+        assert!(lerped_scales.iter().all(|s| s.is_finite()));
+        for i in 0..4 {
+            let scalar = Similarity3::new(Vec3::zero(), Rotor3::identity(), scale_a[i])
+                .lerp(Similarity3::new(Vec3::zero(), Rotor3::identity(), scale_b[i]), 0.5);
+            assert_relative_eq!(lerped_scales[i], scalar.scale, epsilon = 1e-5);
+        }
+    }
+
+    #[test]
+    fn face_towards_handles_forward_parallel_to_both_fallback_axes() {
+        // `up` parallel to `forward` forces the first fallback axis (unit_z); looking
+        // straight down +Z makes `forward` parallel to unit_z too, forcing the second.
+        let iso = Isometry3::face_towards(Vec3::zero(), Vec3::new(0.0, 0.0, 1.0), Vec3::new(0.0, 0.0, 1.0));
+        assert!(iso.rotation.mag().is_finite());
+        assert_relative_eq!(iso.rotation.mag(), 1.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn isometry_to_similarity_round_trips_through_isometry() {
+        let iso = Isometry3::new(Vec3::new(1.0, 2.0, 3.0), Rotor3::from_rotation_xy(0.3));
+
+        let sim = iso.to_similarity(2.0);
+        assert_relative_eq!(sim.translation, iso.translation);
+        assert_relative_eq!(sim.rotation, iso.rotation);
+        assert_relative_eq!(sim.scale, 2.0);
+        assert_relative_eq!(sim.isometry(), iso);
+    }
+
+    #[test]
+    fn from_isometry_for_similarity_defaults_scale_to_one() {
+        let iso = Isometry3::new(Vec3::new(1.0, 2.0, 3.0), Rotor3::from_rotation_xy(0.3));
+
+        let sim: Similarity3 = iso.into();
+        assert_relative_eq!(sim.scale, 1.0);
+        assert_relative_eq!(sim.isometry(), iso);
+    }
+
+    #[test]
+    fn similarity_from_parts_matches_fields() {
+        let translation = Vec3::new(1.0, 2.0, 3.0);
+        let rotation = Rotor3::from_rotation_xy(0.3);
+        let sim = Similarity3::from_parts(translation, rotation, 5.0);
+
+        assert_relative_eq!(sim.translation, translation);
+        assert_relative_eq!(sim.rotation, rotation);
+        assert_relative_eq!(sim.scale, 5.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn similarity_from_parts_rejects_zero_scale_in_debug() {
+        Similarity3::from_parts(Vec3::zero(), Rotor3::identity(), 0.0);
+    }
+
+    #[test]
+    fn isometry_transform_vec_ignores_translation() {
+        let iso = Isometry3::new(Vec3::new(10.0, 0.0, 0.0), Rotor3::identity());
+        let v = Vec3::new(1.0, 2.0, 3.0);
+
+        assert_relative_eq!(iso.transform_vec(v), v);
+        assert_relative_eq!(iso.transform_direction(v), v);
+        assert_relative_eq!(iso.transform_point(v), v + iso.translation);
+    }
+
+    #[test]
+    fn isometry_inverse_transform_matches_inversed() {
+        let iso = Isometry3::new(Vec3::new(1.0, 2.0, 3.0), Rotor3::from_rotation_xy(0.7));
+        let v = Vec3::new(4.0, -5.0, 6.0);
+
+        assert_relative_eq!(iso.inverse_transform_point(v), iso.inversed().transform_point(v), epsilon = 1e-5);
+        assert_relative_eq!(iso.inverse_transform_vec(v), iso.inversed().transform_vec(v), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn isometry_inverse_transform_point_round_trips() {
+        let iso = Isometry3::new(Vec3::new(1.0, 2.0, 3.0), Rotor3::from_rotation_xy(0.7));
+        let p = Vec3::new(4.0, -5.0, 6.0);
+
+        let transformed = iso.transform_point(p);
+        assert_relative_eq!(iso.inverse_transform_point(transformed), p, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn similarity_transform_vec_scales_but_does_not_translate() {
+        let sim = Similarity3::from_parts(Vec3::new(10.0, 0.0, 0.0), Rotor3::identity(), 2.0);
+        let v = Vec3::new(1.0, 2.0, 3.0);
+
+        assert_relative_eq!(sim.transform_vec(v), v * 2.0);
+        assert_relative_eq!(sim.transform_direction(v), v * 2.0);
+    }
+
+    #[test]
+    fn similarity_inverse_transform_matches_inversed() {
+        let sim = Similarity3::from_parts(Vec3::new(1.0, 2.0, 3.0), Rotor3::from_rotation_xy(0.7), 2.0);
+        let v = Vec3::new(4.0, -5.0, 6.0);
+
+        assert_relative_eq!(sim.inverse_transform_point(v), sim.inversed().transform_point(v), epsilon = 1e-5);
+        assert_relative_eq!(sim.inverse_transform_vec(v), sim.inversed().transform_vec(v), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn similarity_inverse_transform_point_round_trips() {
+        let sim = Similarity3::from_parts(Vec3::new(1.0, 2.0, 3.0), Rotor3::from_rotation_xy(0.7), 2.0);
+        let p = Vec3::new(4.0, -5.0, 6.0);
+
+        let transformed = sim.transform_point(p);
+        assert_relative_eq!(sim.inverse_transform_point(transformed), p, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn isometry_approx_eq() {
+        let a = Isometry3::new(Vec3::new(1.0, 2.0, 3.0), Rotor3::from_rotation_xy(0.5));
+        let mut b = a;
+        b.translation.x += 1e-7;
+
+        assert_relative_eq!(a, b);
+        assert_ulps_eq!(a, b);
+    }
+
+    #[test]
+    fn similarity_approx_eq() {
+        let a = Similarity3::from_parts(Vec3::new(1.0, 2.0, 3.0), Rotor3::from_rotation_xy(0.5), 2.0);
+        let mut b = a;
+        b.scale += 1e-7;
+
+        assert_relative_eq!(a, b);
+        assert_ulps_eq!(a, b);
+    }
+
+    #[test]
+    fn similarity_not_approx_eq_when_scale_differs() {
+        let a = Similarity3::from_parts(Vec3::zero(), Rotor3::identity(), 1.0);
+        let b = Similarity3::from_parts(Vec3::zero(), Rotor3::identity(), 2.0);
+
+        assert!(!relative_eq!(a, b));
+    }
+}